@@ -0,0 +1,72 @@
+use axum::{
+    extract::FromRequestParts,
+    http::request::Parts,
+    RequestPartsExt,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{handlers::AppError, AppState};
+
+const TOKEN_TTL_HOURS: i64 = 24;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Claims {
+    sub: Uuid,
+    exp: i64,
+}
+
+/// Identity of the authenticated caller, extracted from a validated `Bearer`
+/// JWT. Handlers that take this as an argument automatically reject
+/// unauthenticated requests with 401 before the handler body runs.
+#[derive(Debug, Clone, Copy)]
+pub struct AccessClaims {
+    pub user_id: Uuid,
+}
+
+/// Issues a signed access token for `user_id`, valid for `TOKEN_TTL_HOURS`.
+pub fn issue_token(secret: &str, user_id: Uuid) -> Result<String, AppError> {
+    let claims = Claims {
+        sub: user_id,
+        exp: (Utc::now() + Duration::hours(TOKEN_TTL_HOURS)).timestamp(),
+    };
+
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(secret.as_bytes()),
+    )
+    .map_err(|_| AppError::Unauthorized)
+}
+
+#[axum::async_trait]
+impl FromRequestParts<AppState> for AccessClaims {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let TypedHeader(Authorization(bearer)) = parts
+            .extract::<TypedHeader<Authorization<Bearer>>>()
+            .await
+            .map_err(|_| AppError::Unauthorized)?;
+
+        let data = decode::<Claims>(
+            bearer.token(),
+            &DecodingKey::from_secret(state.jwt_secret.as_bytes()),
+            &Validation::default(),
+        )
+        .map_err(|_| AppError::Unauthorized)?;
+
+        Ok(AccessClaims {
+            user_id: data.claims.sub,
+        })
+    }
+}
@@ -1,28 +1,60 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use chrono::Utc;
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
-use tracing::error;
+use tracing::{error, warn};
 use uuid::Uuid;
 
 use crate::{
-    models::{CreateTaskRequest, Task, TaskStatus, UpdateTaskRequest},
+    auth::{self, AccessClaims},
+    jobs,
+    models::{
+        BatchItem, BatchItemResult, BatchRequest, BatchResponse, CreateTaskRequest, LoginRequest,
+        LoginResponse, RegisterRequest, ReminderJob, SortOrder, Task, TaskPage, TaskPriority,
+        TaskQuery, TaskSortBy, TaskStatus, UpdateTaskRequest, User,
+    },
     AppState,
 };
 
+const DEFAULT_PAGE_LIMIT: i64 = 20;
+const MAX_PAGE_LIMIT: i64 = 100;
+
+const TASK_COLUMNS: &str = "id, user_id, title, description, priority, status, created_at, \
+     completed_at, start_at, end_at, location, remote, due_at";
+
 #[derive(Debug, Serialize)]
 struct ErrorResponse {
     message: String,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConflictResponse {
+    message: String,
+    conflicting_task_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BatchErrorResponse {
+    message: String,
+    failed_index: usize,
+}
+
 #[derive(Debug)]
 pub enum AppError {
     NotFound,
+    BadRequest(String),
+    Unauthorized,
+    Conflict(Vec<Uuid>),
+    BatchFailed { index: usize, message: String },
     Db(sqlx::Error),
+    Internal(String),
 }
 
 impl From<sqlx::Error> for AppError {
@@ -41,6 +73,32 @@ impl IntoResponse for AppError {
                 }),
             )
                 .into_response(),
+            AppError::BadRequest(message) => {
+                (StatusCode::BAD_REQUEST, Json(ErrorResponse { message })).into_response()
+            }
+            AppError::Unauthorized => (
+                StatusCode::UNAUTHORIZED,
+                Json(ErrorResponse {
+                    message: "Invalid or missing credentials".to_string(),
+                }),
+            )
+                .into_response(),
+            AppError::Conflict(conflicting_task_ids) => (
+                StatusCode::CONFLICT,
+                Json(ConflictResponse {
+                    message: "Task schedule overlaps with an existing task".to_string(),
+                    conflicting_task_ids,
+                }),
+            )
+                .into_response(),
+            AppError::BatchFailed { index, message } => (
+                StatusCode::BAD_REQUEST,
+                Json(BatchErrorResponse {
+                    message,
+                    failed_index: index,
+                }),
+            )
+                .into_response(),
             AppError::Db(err) => {
                 error!(error = ?err, "database error");
                 (
@@ -51,34 +109,489 @@ impl IntoResponse for AppError {
                 )
                     .into_response()
             }
+            AppError::Internal(context) => {
+                error!(context = %context, "internal error");
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(ErrorResponse {
+                        message: "Internal server error".to_string(),
+                    }),
+                )
+                    .into_response()
+            }
         }
     }
 }
 
-pub async fn get_tasks(State(state): State<AppState>) -> Result<Json<Vec<Task>>, AppError> {
-    let tasks = sqlx::query_as::<_, Task>(
-        "SELECT id, title, description, priority, status, created_at, completed_at \
-         FROM tasks ORDER BY created_at DESC",
+/// Encodes the `(created_at, id)` keyset of the last row on a page into an
+/// opaque cursor string.
+fn encode_cursor(created_at: DateTime<Utc>, id: Uuid) -> String {
+    URL_SAFE_NO_PAD.encode(format!("{}|{}", created_at.to_rfc3339(), id))
+}
+
+fn decode_cursor(cursor: &str) -> Result<(DateTime<Utc>, Uuid), AppError> {
+    let invalid = || AppError::BadRequest("invalid cursor".to_string());
+
+    let decoded = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+    let (created_at, id) = decoded.split_once('|').ok_or_else(invalid)?;
+
+    let created_at = DateTime::parse_from_rfc3339(created_at)
+        .map_err(|_| invalid())?
+        .with_timezone(&Utc);
+    let id = Uuid::parse_str(id).map_err(|_| invalid())?;
+
+    Ok((created_at, id))
+}
+
+/// Queues a due-date reminder for `task`, firing around `due_at`.
+async fn enqueue_reminder(
+    pool: &sqlx::PgPool,
+    task: &Task,
+    due_at: DateTime<Utc>,
+) -> Result<(), AppError> {
+    let job = ReminderJob {
+        task_id: task.id,
+        user_id: task.user_id,
+        title: task.title.clone(),
+        due_at,
+    };
+    jobs::enqueue_reminder(pool, &job).await?;
+    Ok(())
+}
+
+/// Brings `task_id`'s queued reminder in line with a create/update/delete:
+/// cancels the outstanding job when `due_at` changed, the task was completed,
+/// or the task was deleted, then re-enqueues one if the new state still
+/// warrants it. `before` is `None` for a brand-new task (nothing to cancel);
+/// `after` is `None` for a deleted task (nothing to (re-)schedule).
+async fn sync_reminder(
+    pool: &sqlx::PgPool,
+    task_id: Uuid,
+    before: Option<(Option<DateTime<Utc>>, TaskStatus)>,
+    after: Option<&Task>,
+) -> Result<(), AppError> {
+    let (before_due_at, before_status) = before.unwrap_or((None, TaskStatus::Pending));
+    let had_pending_job =
+        before_due_at.is_some() && !matches!(before_status, TaskStatus::Completed);
+
+    match after {
+        None => {
+            if had_pending_job {
+                jobs::cancel_reminders(pool, task_id).await?;
+            }
+        }
+        Some(task) => {
+            let due_at_changed = task.due_at != before_due_at;
+            let became_completed = matches!(task.status, TaskStatus::Completed)
+                && !matches!(before_status, TaskStatus::Completed);
+
+            if had_pending_job && (due_at_changed || became_completed) {
+                jobs::cancel_reminders(pool, task_id).await?;
+            }
+
+            if due_at_changed {
+                if let Some(due_at) = task.due_at {
+                    if !matches!(task.status, TaskStatus::Completed) {
+                        enqueue_reminder(pool, task, due_at).await?;
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Short, user-facing description of an `AppError`, used when an error needs
+/// to be embedded in a larger response (e.g. which batch operation failed)
+/// rather than drive the whole response's status code.
+fn describe_error(err: &AppError) -> String {
+    match err {
+        AppError::NotFound => "Task not found".to_string(),
+        AppError::BadRequest(message) => message.clone(),
+        AppError::Unauthorized => "Invalid or missing credentials".to_string(),
+        AppError::Conflict(_) => "Task schedule overlaps with an existing task".to_string(),
+        AppError::BatchFailed { message, .. } => message.clone(),
+        AppError::Db(_) => "Internal server error".to_string(),
+        AppError::Internal(_) => "Internal server error".to_string(),
+    }
+}
+
+/// Returns the ids of the caller's other scheduled, non-remote tasks whose
+/// `[start_at, end_at)` range overlaps `[start_at, end_at)`.
+///
+/// This is a plain check, not a lock: two concurrent requests can each see no
+/// conflict and both insert, landing overlapping schedules. Acceptable for
+/// this CRUD API's scope, but worth knowing if overlap ever needs to be a
+/// hard guarantee rather than a best-effort check.
+async fn find_schedule_conflicts<'e, E>(
+    executor: E,
+    user_id: Uuid,
+    exclude_id: Option<Uuid>,
+    start_at: DateTime<Utc>,
+    end_at: DateTime<Utc>,
+) -> Result<Vec<Uuid>, AppError>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let rows: Vec<(Uuid,)> = sqlx::query_as(
+        "SELECT id FROM tasks \
+         WHERE user_id = $1 \
+           AND ($2::uuid IS NULL OR id != $2) \
+           AND NOT remote \
+           AND start_at IS NOT NULL AND end_at IS NOT NULL \
+           AND start_at < $4 AND end_at > $3",
     )
-    .fetch_all(&state.pool)
+    .bind(user_id)
+    .bind(exclude_id)
+    .bind(start_at)
+    .bind(end_at)
+    .fetch_all(executor)
     .await?;
 
-    Ok(Json(tasks))
+    Ok(rows.into_iter().map(|(id,)| id).collect())
+}
+
+/// Validates a schedule range and, unless the task is remote, checks it
+/// against the caller's other tasks. Shared by `create_task`, `update_task`,
+/// and `apply_batch_item` so the overlap rule can't drift between the
+/// pool-backed and transaction-backed call sites.
+async fn check_schedule<'e, E>(
+    executor: E,
+    user_id: Uuid,
+    exclude_id: Option<Uuid>,
+    start_at: DateTime<Utc>,
+    end_at: DateTime<Utc>,
+    remote: bool,
+) -> Result<(), AppError>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    if start_at >= end_at {
+        return Err(AppError::BadRequest(
+            "startAt must be before endAt".to_string(),
+        ));
+    }
+
+    if !remote {
+        let conflicts =
+            find_schedule_conflicts(executor, user_id, exclude_id, start_at, end_at).await?;
+        if !conflicts.is_empty() {
+            return Err(AppError::Conflict(conflicts));
+        }
+    }
+
+    Ok(())
+}
+
+/// Derives `(status, completed_at)` for a brand-new task.
+fn derive_create_status(status: Option<TaskStatus>) -> (TaskStatus, Option<DateTime<Utc>>) {
+    let status = status.unwrap_or(TaskStatus::Pending);
+    let completed_at = if matches!(status, TaskStatus::Completed) {
+        Some(Utc::now())
+    } else {
+        None
+    };
+    (status, completed_at)
+}
+
+/// Derives `(status, completed_at)` for an existing task being merged with
+/// `new_status`, keeping `completed_at` aligned with status transitions.
+fn derive_update_status(
+    new_status: Option<TaskStatus>,
+    existing_status: TaskStatus,
+    existing_completed_at: Option<DateTime<Utc>>,
+) -> (TaskStatus, Option<DateTime<Utc>>) {
+    let status_was_set = new_status.is_some();
+    let status = new_status.unwrap_or(existing_status);
+    let completed_at = if matches!(status, TaskStatus::Completed) {
+        if existing_status != TaskStatus::Completed || existing_completed_at.is_none() {
+            Some(Utc::now())
+        } else {
+            existing_completed_at
+        }
+    } else if status_was_set {
+        None
+    } else {
+        existing_completed_at
+    };
+    (status, completed_at)
+}
+
+/// Fetches a single task owned by `user_id`, shared by every handler that
+/// needs to load the existing row before merging changes into it.
+async fn fetch_task<'e, E>(executor: E, id: Uuid, user_id: Uuid) -> Result<Option<Task>, AppError>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let sql = format!("SELECT {TASK_COLUMNS} FROM tasks WHERE id = $1 AND user_id = $2");
+    let task = sqlx::query_as::<_, Task>(&sql)
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(executor)
+        .await?;
+
+    Ok(task)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn insert_task<'e, E>(
+    executor: E,
+    user_id: Uuid,
+    title: String,
+    description: String,
+    priority: TaskPriority,
+    status: TaskStatus,
+    completed_at: Option<DateTime<Utc>>,
+    start_at: Option<DateTime<Utc>>,
+    end_at: Option<DateTime<Utc>>,
+    location: Option<String>,
+    remote: bool,
+    due_at: Option<DateTime<Utc>>,
+) -> Result<Task, AppError>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let sql = format!(
+        "INSERT INTO tasks (id, user_id, title, description, priority, status, completed_at, \
+         start_at, end_at, location, remote, due_at) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12) \
+         RETURNING {TASK_COLUMNS}"
+    );
+
+    let task = sqlx::query_as::<_, Task>(&sql)
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(title)
+        .bind(description)
+        .bind(priority)
+        .bind(status)
+        .bind(completed_at)
+        .bind(start_at)
+        .bind(end_at)
+        .bind(location)
+        .bind(remote)
+        .bind(due_at)
+        .fetch_one(executor)
+        .await?;
+
+    Ok(task)
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn update_task_row<'e, E>(
+    executor: E,
+    id: Uuid,
+    user_id: Uuid,
+    title: String,
+    description: String,
+    priority: TaskPriority,
+    status: TaskStatus,
+    completed_at: Option<DateTime<Utc>>,
+    start_at: Option<DateTime<Utc>>,
+    end_at: Option<DateTime<Utc>>,
+    location: Option<String>,
+    remote: bool,
+    due_at: Option<DateTime<Utc>>,
+) -> Result<Option<Task>, AppError>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Postgres>,
+{
+    let sql = format!(
+        "UPDATE tasks \
+         SET title = $1, description = $2, priority = $3, status = $4, completed_at = $5, \
+             start_at = $6, end_at = $7, location = $8, remote = $9, due_at = $10 \
+         WHERE id = $11 AND user_id = $12 \
+         RETURNING {TASK_COLUMNS}"
+    );
+
+    let task = sqlx::query_as::<_, Task>(&sql)
+        .bind(title)
+        .bind(description)
+        .bind(priority)
+        .bind(status)
+        .bind(completed_at)
+        .bind(start_at)
+        .bind(end_at)
+        .bind(location)
+        .bind(remote)
+        .bind(due_at)
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(executor)
+        .await?;
+
+    Ok(task)
+}
+
+pub async fn login(
+    State(state): State<AppState>,
+    Json(payload): Json<LoginRequest>,
+) -> Result<Json<LoginResponse>, AppError> {
+    let user = sqlx::query_as::<_, User>("SELECT id, password_hash FROM users WHERE email = $1")
+        .bind(&payload.email)
+        .fetch_optional(&state.pool)
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+
+    // bcrypt is CPU-bound and synchronous; run it on a blocking thread so one
+    // slow hash doesn't stall every other in-flight request on this worker.
+    let password_hash = user.password_hash.clone();
+    let valid =
+        tokio::task::spawn_blocking(move || bcrypt::verify(&payload.password, &password_hash))
+            .await
+            .map_err(|err| AppError::Internal(err.to_string()))?
+            .map_err(|_| AppError::Unauthorized)?;
+    if !valid {
+        return Err(AppError::Unauthorized);
+    }
+
+    let token = auth::issue_token(&state.jwt_secret, user.id)?;
+    Ok(Json(LoginResponse { token }))
+}
+
+/// Creates a new account and immediately issues it an access token, so a
+/// caller can register and start making authenticated requests in one round
+/// trip.
+pub async fn register(
+    State(state): State<AppState>,
+    Json(payload): Json<RegisterRequest>,
+) -> Result<(StatusCode, Json<LoginResponse>), AppError> {
+    let password = payload.password.clone();
+    let password_hash =
+        tokio::task::spawn_blocking(move || bcrypt::hash(&password, bcrypt::DEFAULT_COST))
+            .await
+            .map_err(|err| AppError::Internal(err.to_string()))?
+            .map_err(|_| AppError::BadRequest("could not process password".to_string()))?;
+
+    let user = sqlx::query_as::<_, User>(
+        "INSERT INTO users (id, email, password_hash) VALUES ($1, $2, $3) \
+         RETURNING id, password_hash",
+    )
+    .bind(Uuid::new_v4())
+    .bind(&payload.email)
+    .bind(password_hash)
+    .fetch_one(&state.pool)
+    .await
+    .map_err(|err| match &err {
+        sqlx::Error::Database(db_err) if db_err.code().as_deref() == Some("23505") => {
+            AppError::BadRequest("email is already registered".to_string())
+        }
+        _ => AppError::Db(err),
+    })?;
+
+    let token = auth::issue_token(&state.jwt_secret, user.id)?;
+    Ok((StatusCode::CREATED, Json(LoginResponse { token })))
+}
+
+pub async fn get_tasks(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Query(query): Query<TaskQuery>,
+) -> Result<Json<TaskPage>, AppError> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_LIMIT)
+        .clamp(1, MAX_PAGE_LIMIT);
+
+    // Keyset pagination only makes sense against a stable, total order, so a
+    // cursor pins the listing to created_at/id regardless of sortBy (order
+    // still picks the direction); sortBy stays available for one-shot,
+    // unpaginated requests.
+    if query.cursor.is_some() || query.sort_by.is_none() {
+        let cursor = query.cursor.as_deref().map(decode_cursor).transpose()?;
+        let (cursor_created_at, cursor_id) = cursor.unzip();
+
+        // The cursor comparison and ORDER BY direction must agree, or paging
+        // forward would walk the keyset backwards.
+        let (cursor_cmp, order) = match query.order.unwrap_or(SortOrder::Desc) {
+            SortOrder::Desc => ("<", "DESC"),
+            SortOrder::Asc => (">", "ASC"),
+        };
+
+        let sql = format!(
+            "SELECT {TASK_COLUMNS} \
+             FROM tasks \
+             WHERE user_id = $1 \
+               AND ($2::task_status IS NULL OR status = $2) \
+               AND ($3::task_priority IS NULL OR priority = $3) \
+               AND ($4::text IS NULL OR (title ILIKE '%' || $4 || '%' OR description ILIKE '%' || $4 || '%')) \
+               AND ($5::timestamptz IS NULL OR created_at >= $5) \
+               AND ($6::timestamptz IS NULL OR created_at <= $6) \
+               AND ($7::timestamptz IS NULL OR (created_at, id) {cursor_cmp} ($7, $8)) \
+             ORDER BY created_at {order}, id {order} \
+             LIMIT $9"
+        );
+
+        let tasks = sqlx::query_as::<_, Task>(&sql)
+            .bind(claims.user_id)
+            .bind(query.status)
+            .bind(query.priority)
+            .bind(query.search)
+            .bind(query.created_after)
+            .bind(query.created_before)
+            .bind(cursor_created_at)
+            .bind(cursor_id)
+            .bind(limit)
+            .fetch_all(&state.pool)
+            .await?;
+
+        let next_cursor = if tasks.len() as i64 == limit {
+            tasks.last().map(|t| encode_cursor(t.created_at, t.id))
+        } else {
+            None
+        };
+
+        return Ok(Json(TaskPage {
+            items: tasks,
+            next_cursor,
+        }));
+    }
+
+    let sort_column = query.sort_by.unwrap_or(TaskSortBy::CreatedAt).column();
+    let order = query.order.unwrap_or(SortOrder::Desc).as_sql();
+
+    // Nullable-filter pattern: one prepared statement covers every combination
+    // of filters, with unset ones short-circuited by the `IS NULL` check. This
+    // branch can't keyset-paginate on an arbitrary sort column, but `limit`
+    // still bounds the result set like every other listing.
+    let sql = format!(
+        "SELECT {TASK_COLUMNS} \
+         FROM tasks \
+         WHERE user_id = $1 \
+           AND ($2::task_status IS NULL OR status = $2) \
+           AND ($3::task_priority IS NULL OR priority = $3) \
+           AND ($4::text IS NULL OR (title ILIKE '%' || $4 || '%' OR description ILIKE '%' || $4 || '%')) \
+           AND ($5::timestamptz IS NULL OR created_at >= $5) \
+           AND ($6::timestamptz IS NULL OR created_at <= $6) \
+         ORDER BY {sort_column} {order} \
+         LIMIT $7"
+    );
+
+    let tasks = sqlx::query_as::<_, Task>(&sql)
+        .bind(claims.user_id)
+        .bind(query.status)
+        .bind(query.priority)
+        .bind(query.search)
+        .bind(query.created_after)
+        .bind(query.created_before)
+        .bind(limit)
+        .fetch_all(&state.pool)
+        .await?;
+
+    Ok(Json(TaskPage {
+        items: tasks,
+        next_cursor: None,
+    }))
 }
 
 pub async fn get_task(
     State(state): State<AppState>,
+    claims: AccessClaims,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Task>, AppError> {
-    let task = sqlx::query_as::<_, Task>(
-        "SELECT id, title, description, priority, status, created_at, completed_at \
-         FROM tasks WHERE id = $1",
-    )
-    .bind(id)
-    .fetch_optional(&state.pool)
-    .await?;
-
-    match task {
+    match fetch_task(&state.pool, id, claims.user_id).await? {
         Some(task) => Ok(Json(task)),
         None => Err(AppError::NotFound),
     }
@@ -86,46 +599,56 @@ pub async fn get_task(
 
 pub async fn create_task(
     State(state): State<AppState>,
+    claims: AccessClaims,
     Json(payload): Json<CreateTaskRequest>,
 ) -> Result<(StatusCode, Json<Task>), AppError> {
-    let status = payload.status.unwrap_or(TaskStatus::Pending);
-    let completed_at = if matches!(status, TaskStatus::Completed) {
-        Some(Utc::now())
-    } else {
-        None
-    };
+    let (status, completed_at) = derive_create_status(payload.status);
 
-    let task = sqlx::query_as::<_, Task>(
-        "INSERT INTO tasks (id, title, description, priority, status, completed_at) \
-         VALUES ($1, $2, $3, $4, $5, $6) \
-         RETURNING id, title, description, priority, status, created_at, completed_at",
+    if let (Some(start_at), Some(end_at)) = (payload.start_at, payload.end_at) {
+        check_schedule(
+            &state.pool,
+            claims.user_id,
+            None,
+            start_at,
+            end_at,
+            payload.remote,
+        )
+        .await?;
+    }
+
+    let task = insert_task(
+        &state.pool,
+        claims.user_id,
+        payload.title,
+        payload.description,
+        payload.priority,
+        status,
+        completed_at,
+        payload.start_at,
+        payload.end_at,
+        payload.location,
+        payload.remote,
+        payload.due_at,
     )
-    .bind(Uuid::new_v4())
-    .bind(payload.title)
-    .bind(payload.description)
-    .bind(payload.priority)
-    .bind(status)
-    .bind(completed_at)
-    .fetch_one(&state.pool)
     .await?;
 
+    // The task row is already durably committed at this point, so a reminder
+    // write failure is logged and swallowed rather than turning a successful
+    // create into a 500 (mirrors the batch path's reconciliation loop below).
+    if let Err(err) = sync_reminder(&state.pool, task.id, None, Some(&task)).await {
+        warn!(error = ?err, task_id = %task.id, "failed to schedule reminder job after create");
+    }
+
     Ok((StatusCode::CREATED, Json(task)))
 }
 
 pub async fn update_task(
     State(state): State<AppState>,
+    claims: AccessClaims,
     Path(id): Path<Uuid>,
     Json(payload): Json<UpdateTaskRequest>,
 ) -> Result<Json<Task>, AppError> {
-    let existing = sqlx::query_as::<_, Task>(
-        "SELECT id, title, description, priority, status, created_at, completed_at \
-         FROM tasks WHERE id = $1",
-    )
-    .bind(id)
-    .fetch_optional(&state.pool)
-    .await?;
-
-    let existing = match existing {
+    let existing = match fetch_task(&state.pool, id, claims.user_id).await? {
         Some(task) => task,
         None => return Err(AppError::NotFound),
     };
@@ -136,55 +659,80 @@ pub async fn update_task(
         priority: existing_priority,
         status: existing_status,
         completed_at: existing_completed_at,
+        start_at: existing_start_at,
+        end_at: existing_end_at,
+        location: existing_location,
+        remote: existing_remote,
+        due_at: existing_due_at,
         ..
     } = existing;
 
-    let status_was_set = payload.status.is_some();
-    let status = payload.status.unwrap_or(existing_status);
-    // Keep completed_at aligned with status transitions.
-    let completed_at = if matches!(status, TaskStatus::Completed) {
-        if existing_status != TaskStatus::Completed || existing_completed_at.is_none() {
-            Some(Utc::now())
-        } else {
-            existing_completed_at
-        }
-    } else if status_was_set {
-        None
-    } else {
-        existing_completed_at
-    };
+    let (status, completed_at) =
+        derive_update_status(payload.status, existing_status, existing_completed_at);
 
     let title = payload.title.unwrap_or(existing_title);
     let description = payload.description.unwrap_or(existing_description);
     let priority = payload.priority.unwrap_or(existing_priority);
+    let start_at = payload.start_at.or(existing_start_at);
+    let end_at = payload.end_at.or(existing_end_at);
+    let location = payload.location.or(existing_location);
+    let remote = payload.remote.unwrap_or(existing_remote);
+    let due_at = payload.due_at.or(existing_due_at);
 
-    let task = sqlx::query_as::<_, Task>(
-        "UPDATE tasks \
-         SET title = $1, description = $2, priority = $3, status = $4, completed_at = $5 \
-         WHERE id = $6 \
-         RETURNING id, title, description, priority, status, created_at, completed_at",
+    if let (Some(start_at), Some(end_at)) = (start_at, end_at) {
+        check_schedule(
+            &state.pool,
+            claims.user_id,
+            Some(id),
+            start_at,
+            end_at,
+            remote,
+        )
+        .await?;
+    }
+
+    let task = update_task_row(
+        &state.pool,
+        id,
+        claims.user_id,
+        title,
+        description,
+        priority,
+        status,
+        completed_at,
+        start_at,
+        end_at,
+        location,
+        remote,
+        due_at,
     )
-    .bind(title)
-    .bind(description)
-    .bind(priority)
-    .bind(status)
-    .bind(completed_at)
-    .bind(id)
-    .fetch_optional(&state.pool)
-    .await?;
+    .await?
+    .ok_or(AppError::NotFound)?;
 
-    match task {
-        Some(task) => Ok(Json(task)),
-        None => Err(AppError::NotFound),
+    // Same reasoning as create_task: the update already landed, so treat
+    // reminder reconciliation as best-effort rather than failing the request.
+    if let Err(err) = sync_reminder(
+        &state.pool,
+        task.id,
+        Some((existing_due_at, existing_status)),
+        Some(&task),
+    )
+    .await
+    {
+        warn!(error = ?err, task_id = %task.id, "failed to reconcile reminder job after update");
     }
+
+    Ok(Json(task))
 }
 
 pub async fn delete_task(
     State(state): State<AppState>,
+    claims: AccessClaims,
     Path(id): Path<Uuid>,
 ) -> Result<StatusCode, AppError> {
-    let result = sqlx::query("DELETE FROM tasks WHERE id = $1")
+    let result = sqlx::query("DELETE FROM tasks WHERE id = $1 AND user_id = $2")
         .bind(id)
+        .bind(claims.user_id)
         .execute(&state.pool)
         .await?;
 
@@ -192,5 +740,326 @@ pub async fn delete_task(
         return Err(AppError::NotFound);
     }
 
+    // The task is already gone; a stale reminder job outliving it is a minor
+    // annoyance, so log and move on instead of telling the caller the delete
+    // failed when it didn't.
+    if let Err(err) = jobs::cancel_reminders(&state.pool, id).await {
+        warn!(error = ?err, task_id = %id, "failed to cancel reminder job after delete");
+    }
+
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// The before/after task state `apply_batch_item` hands back so `batch_tasks`
+/// can reconcile reminder jobs once the whole batch has committed (reminder
+/// writes are a best-effort side effect, not part of the transaction).
+enum ReminderSync {
+    Created {
+        task: Task,
+    },
+    Updated {
+        before_due_at: Option<DateTime<Utc>>,
+        before_status: TaskStatus,
+        after: Task,
+    },
+    Deleted {
+        task_id: Uuid,
+    },
+}
+
+async fn apply_batch_item(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    user_id: Uuid,
+    item: BatchItem,
+) -> Result<(BatchItemResult, ReminderSync), AppError> {
+    match item {
+        BatchItem::Create(payload) => {
+            let (status, completed_at) = derive_create_status(payload.status);
+
+            if let (Some(start_at), Some(end_at)) = (payload.start_at, payload.end_at) {
+                check_schedule(&mut **tx, user_id, None, start_at, end_at, payload.remote).await?;
+            }
+
+            let task = insert_task(
+                &mut **tx,
+                user_id,
+                payload.title,
+                payload.description,
+                payload.priority,
+                status,
+                completed_at,
+                payload.start_at,
+                payload.end_at,
+                payload.location,
+                payload.remote,
+                payload.due_at,
+            )
+            .await?;
+
+            let sync = ReminderSync::Created { task: task.clone() };
+
+            Ok((
+                BatchItemResult {
+                    op: "create".to_string(),
+                    task: Some(task),
+                },
+                sync,
+            ))
+        }
+        BatchItem::Update { id, changes } => {
+            let existing = fetch_task(&mut **tx, id, user_id)
+                .await?
+                .ok_or(AppError::NotFound)?;
+
+            let Task {
+                title: existing_title,
+                description: existing_description,
+                priority: existing_priority,
+                status: existing_status,
+                completed_at: existing_completed_at,
+                start_at: existing_start_at,
+                end_at: existing_end_at,
+                location: existing_location,
+                remote: existing_remote,
+                due_at: existing_due_at,
+                ..
+            } = existing;
+
+            let (status, completed_at) =
+                derive_update_status(changes.status, existing_status, existing_completed_at);
+
+            let title = changes.title.unwrap_or(existing_title);
+            let description = changes.description.unwrap_or(existing_description);
+            let priority = changes.priority.unwrap_or(existing_priority);
+            let start_at = changes.start_at.or(existing_start_at);
+            let end_at = changes.end_at.or(existing_end_at);
+            let location = changes.location.or(existing_location);
+            let remote = changes.remote.unwrap_or(existing_remote);
+            let due_at = changes.due_at.or(existing_due_at);
+
+            if let (Some(start_at), Some(end_at)) = (start_at, end_at) {
+                check_schedule(&mut **tx, user_id, Some(id), start_at, end_at, remote).await?;
+            }
+
+            let task = update_task_row(
+                &mut **tx,
+                id,
+                user_id,
+                title,
+                description,
+                priority,
+                status,
+                completed_at,
+                start_at,
+                end_at,
+                location,
+                remote,
+                due_at,
+            )
+            .await?
+            .ok_or(AppError::NotFound)?;
+
+            let sync = ReminderSync::Updated {
+                before_due_at: existing_due_at,
+                before_status: existing_status,
+                after: task.clone(),
+            };
+
+            Ok((
+                BatchItemResult {
+                    op: "update".to_string(),
+                    task: Some(task),
+                },
+                sync,
+            ))
+        }
+        BatchItem::Delete { id } => {
+            let result = sqlx::query("DELETE FROM tasks WHERE id = $1 AND user_id = $2")
+                .bind(id)
+                .bind(user_id)
+                .execute(&mut **tx)
+                .await?;
+
+            if result.rows_affected() == 0 {
+                return Err(AppError::NotFound);
+            }
+
+            Ok((
+                BatchItemResult {
+                    op: "delete".to_string(),
+                    task: None,
+                },
+                ReminderSync::Deleted { task_id: id },
+            ))
+        }
+    }
+}
+
+/// Applies a batch of mixed create/update/delete operations inside a single
+/// transaction: either all operations land, or the index of the first
+/// failure is reported and nothing is persisted.
+pub async fn batch_tasks(
+    State(state): State<AppState>,
+    claims: AccessClaims,
+    Json(payload): Json<BatchRequest>,
+) -> Result<Json<BatchResponse>, AppError> {
+    let mut tx = state.pool.begin().await?;
+    let mut results = Vec::with_capacity(payload.operations.len());
+    let mut reminder_syncs = Vec::with_capacity(payload.operations.len());
+
+    for (index, item) in payload.operations.into_iter().enumerate() {
+        match apply_batch_item(&mut tx, claims.user_id, item).await {
+            Ok((result, sync)) => {
+                results.push(result);
+                reminder_syncs.push(sync);
+            }
+            Err(err) => {
+                let message = describe_error(&err);
+                tx.rollback().await.ok();
+                return Err(AppError::BatchFailed { index, message });
+            }
+        }
+    }
+
+    tx.commit().await?;
+
+    for sync in reminder_syncs {
+        let outcome = match sync {
+            ReminderSync::Created { task } => {
+                sync_reminder(&state.pool, task.id, None, Some(&task)).await
+            }
+            ReminderSync::Updated {
+                before_due_at,
+                before_status,
+                after,
+            } => {
+                sync_reminder(
+                    &state.pool,
+                    after.id,
+                    Some((before_due_at, before_status)),
+                    Some(&after),
+                )
+                .await
+            }
+            ReminderSync::Deleted { task_id } => jobs::cancel_reminders(&state.pool, task_id)
+                .await
+                .map_err(AppError::from),
+        };
+
+        if let Err(err) = outcome {
+            warn!(error = ?err, "failed to reconcile reminder job after batch commit");
+        }
+    }
+
+    Ok(Json(BatchResponse { results }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn seed_user(pool: &sqlx::PgPool) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query("INSERT INTO users (id, email, password_hash) VALUES ($1, $2, $3)")
+            .bind(id)
+            .bind(format!("{id}@example.com"))
+            .bind("unused-hash")
+            .execute(pool)
+            .await
+            .unwrap();
+        id
+    }
+
+    async fn seed_task(pool: &sqlx::PgPool, user_id: Uuid, title: &str) -> Uuid {
+        let id = Uuid::new_v4();
+        sqlx::query(
+            "INSERT INTO tasks (id, user_id, title, description, priority, status) \
+             VALUES ($1, $2, $3, '', 'low', 'pending')",
+        )
+        .bind(id)
+        .bind(user_id)
+        .bind(title)
+        .execute(pool)
+        .await
+        .unwrap();
+        id
+    }
+
+    #[sqlx::test]
+    async fn find_schedule_conflicts_detects_overlap_but_not_adjacency(pool: sqlx::PgPool) {
+        let user_id = seed_user(&pool).await;
+        let start = Utc::now();
+        let end = start + chrono::Duration::hours(1);
+
+        sqlx::query(
+            "INSERT INTO tasks (id, user_id, title, description, priority, status, start_at, end_at, remote) \
+             VALUES ($1, $2, 'existing', '', 'medium', 'pending', $3, $4, false)",
+        )
+        .bind(Uuid::new_v4())
+        .bind(user_id)
+        .bind(start)
+        .bind(end)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let overlapping_start = start + chrono::Duration::minutes(30);
+        let overlapping_end = end + chrono::Duration::minutes(30);
+        let conflicts =
+            find_schedule_conflicts(&pool, user_id, None, overlapping_start, overlapping_end)
+                .await
+                .unwrap();
+        assert_eq!(conflicts.len(), 1);
+
+        // Back-to-back ranges share an endpoint but don't overlap.
+        let adjacent_conflicts =
+            find_schedule_conflicts(&pool, user_id, None, end, end + chrono::Duration::hours(1))
+                .await
+                .unwrap();
+        assert!(adjacent_conflicts.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn batch_tasks_rolls_back_every_operation_on_first_failure(pool: sqlx::PgPool) {
+        let user_id = seed_user(&pool).await;
+        let valid_id = seed_task(&pool, user_id, "keep me").await;
+
+        let state = AppState {
+            pool: pool.clone(),
+            jwt_secret: "test-secret".into(),
+        };
+        let claims = AccessClaims { user_id };
+        let payload = BatchRequest {
+            operations: vec![
+                BatchItem::Update {
+                    id: valid_id,
+                    changes: UpdateTaskRequest {
+                        title: Some("renamed".to_string()),
+                        description: None,
+                        priority: None,
+                        status: None,
+                        start_at: None,
+                        end_at: None,
+                        location: None,
+                        remote: None,
+                        due_at: None,
+                    },
+                },
+                BatchItem::Delete { id: Uuid::new_v4() },
+            ],
+        };
+
+        let result = batch_tasks(State(state), claims, Json(payload)).await;
+        assert!(matches!(
+            result,
+            Err(AppError::BatchFailed { index: 1, .. })
+        ));
+
+        let title: String = sqlx::query_scalar("SELECT title FROM tasks WHERE id = $1")
+            .bind(valid_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(title, "keep me");
+    }
+}
@@ -0,0 +1,235 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use sqlx::{types::Json as SqlxJson, PgPool};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use crate::models::ReminderJob;
+
+const REMINDER_QUEUE: &str = "reminders";
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+const STALE_JOB_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Delivery backend for a fired reminder. The default `LogNotifier` just logs;
+/// real delivery (push, email, ...) can be added by implementing this trait.
+pub trait Notifier: Send + Sync {
+    fn notify(&self, job: &ReminderJob);
+}
+
+pub struct LogNotifier;
+
+impl Notifier for LogNotifier {
+    fn notify(&self, job: &ReminderJob) {
+        info!(task_id = %job.task_id, user_id = %job.user_id, title = %job.title, due_at = %job.due_at, "reminder fired");
+    }
+}
+
+/// Enqueues a reminder job for `task_id`, scheduled to fire at `due_at`.
+pub async fn enqueue_reminder(pool: &PgPool, job: &ReminderJob) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO job_queue (id, queue, job, status, run_at, task_id) \
+         VALUES ($1, $2, $3, 'new', $4, $5)",
+    )
+    .bind(Uuid::new_v4())
+    .bind(REMINDER_QUEUE)
+    .bind(SqlxJson(job))
+    .bind(job.due_at)
+    .bind(job.task_id)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Removes any not-yet-fired reminder jobs queued for `task_id`, used when the
+/// task they belong to is rescheduled, completed, or deleted.
+pub async fn cancel_reminders(pool: &PgPool, task_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM job_queue WHERE queue = $1 AND task_id = $2 AND status = 'new'")
+        .bind(REMINDER_QUEUE)
+        .bind(task_id)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ClaimedJob {
+    id: Uuid,
+    queue: String,
+    job: SqlxJson<ReminderJob>,
+}
+
+/// Atomically claims the oldest due `new` job (one whose `run_at` has
+/// passed), marking it `running` with a fresh heartbeat so other workers skip
+/// it. Jobs scheduled for the future are left alone until their time comes.
+async fn claim_job(pool: &PgPool) -> Result<Option<ClaimedJob>, sqlx::Error> {
+    sqlx::query_as::<_, ClaimedJob>(
+        "UPDATE job_queue \
+         SET status = 'running', heartbeat = NOW() \
+         WHERE id = ( \
+             SELECT id FROM job_queue \
+             WHERE status = 'new' AND run_at <= NOW() \
+             ORDER BY run_at, id \
+             FOR UPDATE SKIP LOCKED \
+             LIMIT 1 \
+         ) \
+         RETURNING id, queue, job",
+    )
+    .fetch_optional(pool)
+    .await
+}
+
+async fn refresh_heartbeat(pool: &PgPool, job_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE job_queue SET heartbeat = NOW() WHERE id = $1")
+        .bind(job_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+async fn delete_job(pool: &PgPool, job_id: Uuid) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM job_queue WHERE id = $1")
+        .bind(job_id)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+/// Re-queues jobs left `running` by a worker that crashed before finishing,
+/// so they get picked up again instead of being lost.
+pub async fn recover_stale_jobs(pool: &PgPool) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "UPDATE job_queue \
+         SET status = 'new', heartbeat = NULL \
+         WHERE status = 'running' \
+           AND heartbeat < NOW() - ($1 || ' seconds')::interval",
+    )
+    .bind(STALE_JOB_TIMEOUT.as_secs().to_string())
+    .execute(pool)
+    .await?;
+
+    if result.rows_affected() > 0 {
+        warn!(count = result.rows_affected(), "recovered stale jobs after restart");
+    }
+
+    Ok(result.rows_affected())
+}
+
+async fn process_job(pool: &PgPool, notifier: &dyn Notifier, claimed: ClaimedJob) {
+    let ClaimedJob { id, queue, job } = claimed;
+
+    if queue == REMINDER_QUEUE {
+        // Real delivery could take longer than one heartbeat interval, so we
+        // refresh it in the background for the duration of the notify call.
+        let heartbeat_pool = pool.clone();
+        let heartbeat_job_id = id;
+        let heartbeat_task = tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(HEARTBEAT_INTERVAL).await;
+                if refresh_heartbeat(&heartbeat_pool, heartbeat_job_id).await.is_err() {
+                    break;
+                }
+            }
+        });
+
+        notifier.notify(&job.0);
+        heartbeat_task.abort();
+    } else {
+        warn!(queue = %queue, "unknown job queue, dropping job");
+    }
+
+    if let Err(err) = delete_job(pool, id).await {
+        error!(error = ?err, job_id = %id, "failed to remove completed job");
+    }
+}
+
+/// Background worker loop: claims one job at a time with `FOR UPDATE SKIP
+/// LOCKED`, fires it through `notifier`, and polls again when the queue is
+/// empty. Intended to be spawned once from `main` per process.
+pub async fn run_worker(pool: PgPool, notifier: Arc<dyn Notifier>) {
+    loop {
+        match claim_job(&pool).await {
+            Ok(Some(claimed)) => process_job(&pool, notifier.as_ref(), claimed).await,
+            Ok(None) => tokio::time::sleep(POLL_INTERVAL).await,
+            Err(err) => {
+                error!(error = ?err, "failed to claim job");
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_job(task_id: Uuid) -> ReminderJob {
+        ReminderJob {
+            task_id,
+            user_id: Uuid::new_v4(),
+            title: "renew license".to_string(),
+            due_at: chrono::Utc::now(),
+        }
+    }
+
+    #[sqlx::test]
+    async fn claim_job_ignores_jobs_not_yet_due(pool: PgPool) {
+        let mut job = sample_job(Uuid::new_v4());
+        job.due_at = chrono::Utc::now() + chrono::Duration::hours(1);
+        enqueue_reminder(&pool, &job).await.unwrap();
+
+        assert!(claim_job(&pool).await.unwrap().is_none());
+    }
+
+    #[sqlx::test]
+    async fn claim_job_picks_up_due_jobs_exactly_once(pool: PgPool) {
+        let job = sample_job(Uuid::new_v4());
+        enqueue_reminder(&pool, &job).await.unwrap();
+
+        let claimed = claim_job(&pool)
+            .await
+            .unwrap()
+            .expect("a due job should be claimable");
+        assert_eq!(claimed.queue, REMINDER_QUEUE);
+
+        // It's now 'running', so a second claim must not pick it up again.
+        assert!(claim_job(&pool).await.unwrap().is_none());
+    }
+
+    #[sqlx::test]
+    async fn recover_stale_jobs_requeues_past_the_timeout(pool: PgPool) {
+        let job = sample_job(Uuid::new_v4());
+        enqueue_reminder(&pool, &job).await.unwrap();
+        claim_job(&pool).await.unwrap().expect("job should be claimable");
+
+        // Simulate a worker that crashed mid-job: its heartbeat stopped
+        // refreshing well before STALE_JOB_TIMEOUT.
+        sqlx::query("UPDATE job_queue SET heartbeat = NOW() - INTERVAL '10 minutes'")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let recovered = recover_stale_jobs(&pool).await.unwrap();
+        assert_eq!(recovered, 1);
+        assert!(claim_job(&pool).await.unwrap().is_some());
+    }
+
+    #[sqlx::test]
+    async fn cancel_reminders_only_removes_the_given_tasks_pending_job(pool: PgPool) {
+        let task_id = Uuid::new_v4();
+        let other_task_id = Uuid::new_v4();
+        enqueue_reminder(&pool, &sample_job(task_id)).await.unwrap();
+        enqueue_reminder(&pool, &sample_job(other_task_id)).await.unwrap();
+
+        cancel_reminders(&pool, task_id).await.unwrap();
+
+        let remaining: i64 = sqlx::query_scalar("SELECT count(*) FROM job_queue")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 1);
+    }
+}
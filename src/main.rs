@@ -1,10 +1,16 @@
+mod auth;
 mod handlers;
+mod jobs;
 mod models;
 
-use axum::{routing::get, Router};
+use axum::{
+    routing::{get, post},
+    Router,
+};
 use dotenv::dotenv;
 use sqlx::postgres::PgPoolOptions;
 use std::env;
+use std::sync::Arc;
 use tower_http::cors::{Any, CorsLayer};
 use tracing::info;
 use tracing_subscriber::EnvFilter;
@@ -12,6 +18,7 @@ use tracing_subscriber::EnvFilter;
 #[derive(Clone)]
 pub struct AppState {
     pub pool: sqlx::PgPool,
+    pub jwt_secret: Arc<str>,
 }
 
 #[tokio::main]
@@ -22,6 +29,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt().with_env_filter(filter).init();
 
     let database_url = env::var("DATABASE_URL").map_err(|_| "DATABASE_URL must be set")?;
+    // Read once at startup and fail fast, rather than panicking deep inside a
+    // request handler the first time a token needs signing or verifying.
+    let jwt_secret: Arc<str> = env::var("JWT_SECRET")
+        .map_err(|_| "JWT_SECRET must be set")?
+        .into();
+
     let pool = PgPoolOptions::new()
         .max_connections(5)
         .connect(&database_url)
@@ -30,7 +43,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Ensure the database schema is ready on startup.
     sqlx::migrate!("./migrations").run(&pool).await?;
 
-    let state = AppState { pool };
+    // A worker may have crashed mid-job on a previous run; put its leftovers
+    // back in the queue before starting a fresh worker.
+    jobs::recover_stale_jobs(&pool).await?;
+    tokio::spawn(jobs::run_worker(pool.clone(), Arc::new(jobs::LogNotifier)));
+
+    let state = AppState { pool, jwt_secret };
 
     let cors = CorsLayer::new()
         .allow_origin(Any)
@@ -38,7 +56,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .allow_headers(Any);
 
     let app = Router::new()
+        .route("/auth/register", post(handlers::register))
+        .route("/auth/login", post(handlers::login))
         .route("/tasks", get(handlers::get_tasks).post(handlers::create_task))
+        .route("/tasks/batch", post(handlers::batch_tasks))
         .route(
             "/tasks/:id",
             get(handlers::get_task)
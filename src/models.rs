@@ -25,12 +25,18 @@ pub enum TaskStatus {
 #[serde(rename_all = "camelCase")]
 pub struct Task {
     pub id: Uuid,
+    pub user_id: Uuid,
     pub title: String,
     pub description: String,
     pub priority: TaskPriority,
     pub status: TaskStatus,
     pub created_at: DateTime<Utc>,
     pub completed_at: Option<DateTime<Utc>>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub location: Option<String>,
+    pub remote: bool,
+    pub due_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -40,6 +46,12 @@ pub struct CreateTaskRequest {
     pub description: String,
     pub priority: TaskPriority,
     pub status: Option<TaskStatus>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub location: Option<String>,
+    #[serde(default)]
+    pub remote: bool,
+    pub due_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -49,4 +61,143 @@ pub struct UpdateTaskRequest {
     pub description: Option<String>,
     pub priority: Option<TaskPriority>,
     pub status: Option<TaskStatus>,
+    pub start_at: Option<DateTime<Utc>>,
+    pub end_at: Option<DateTime<Utc>>,
+    pub location: Option<String>,
+    pub remote: Option<bool>,
+    pub due_at: Option<DateTime<Utc>>,
+}
+
+/// Column a `GET /tasks` listing may be sorted by. Kept as an enum (rather than
+/// accepting a raw column name) so the handler can map it to a whitelisted
+/// identifier instead of interpolating client input into SQL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskSortBy {
+    CreatedAt,
+    Priority,
+    Status,
+    Title,
+}
+
+impl TaskSortBy {
+    pub fn column(self) -> &'static str {
+        match self {
+            TaskSortBy::CreatedAt => "created_at",
+            TaskSortBy::Priority => "priority",
+            TaskSortBy::Status => "status",
+            TaskSortBy::Title => "title",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl SortOrder {
+    pub fn as_sql(self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+/// Query-string parameters accepted by `GET /tasks`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskQuery {
+    pub status: Option<TaskStatus>,
+    pub priority: Option<TaskPriority>,
+    pub search: Option<String>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    pub sort_by: Option<TaskSortBy>,
+    pub order: Option<SortOrder>,
+    pub limit: Option<i64>,
+    pub cursor: Option<String>,
+}
+
+/// A page of tasks returned by `GET /tasks`. `next_cursor` is only present
+/// when a full page was returned, signalling there may be more to fetch.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskPage {
+    pub items: Vec<Task>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct User {
+    pub id: Uuid,
+    pub password_hash: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterRequest {
+    pub email: String,
+    pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LoginResponse {
+    pub token: String,
+}
+
+/// Payload of a job on the `"reminders"` queue of the `job_queue` table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReminderJob {
+    pub task_id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub due_at: DateTime<Utc>,
+}
+
+/// A single operation within a `POST /tasks/batch` request.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum BatchItem {
+    Create(CreateTaskRequest),
+    Update {
+        id: Uuid,
+        #[serde(flatten)]
+        changes: UpdateTaskRequest,
+    },
+    Delete {
+        id: Uuid,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchRequest {
+    pub operations: Vec<BatchItem>,
+}
+
+/// Outcome of one batch operation. `task` is `None` for a successful delete.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchItemResult {
+    pub op: String,
+    pub task: Option<Task>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchResponse {
+    pub results: Vec<BatchItemResult>,
 }